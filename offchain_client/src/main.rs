@@ -11,9 +11,21 @@
 //! communication, including SCALE encoding/decoding of parameters and proper
 //! JSON-RPC request construction for Substrate-based nodes.
 
-use clap::{Parser, Subcommand};
+use clap::{CommandFactory, Parser, Subcommand};
+use clap_complete::Shell;
+use serde::{Deserialize, Serialize};
 use serde_json::json;
 use rand::Rng; // For generating a random part of the simulated hash
+use sp_core::crypto::{Pair as _, Ss58Codec};
+use sp_core::sr25519;
+use std::ffi::OsString;
+
+/// Environment variable supplying `--node-url` when the flag is not given.
+const NODE_URL_ENV: &str = "PENSION_NODE_URL";
+/// Environment variable supplying `--contract-address` when the flag is not given.
+const CONTRACT_ADDRESS_ENV: &str = "PENSION_CONTRACT_ADDRESS";
+const DEFAULT_NODE_URL: &str = "http://localhost:9944";
+const DEFAULT_CONTRACT_ADDRESS: &str = "5C4hrfjw9DjXZTzV3MwzstNcxkN6odQVsreqgradKZLGHG8L"; // Dummy contract address
 
 /// Main CLI structure for parsing command-line arguments.
 ///
@@ -26,14 +38,48 @@ struct Cli {
     #[clap(subcommand)]
     command: Commands,
 
-    /// The URL of the Substrate node to connect to.
-    #[clap(long, default_value = "http://localhost:9944")]
-    node_url: String,
+    /// The URL of the Substrate node to connect to [default: http://localhost:9944].
+    /// Can also be set via the `PENSION_NODE_URL` environment variable; the flag takes precedence.
+    #[clap(long)]
+    node_url: Option<String>,
 
     /// The address of the deployed `pension_manager` smart contract.
+    /// Can also be set via the `PENSION_CONTRACT_ADDRESS` environment variable; the flag takes precedence.
+    #[clap(long)]
+    contract_address: Option<String>,
+
+    /// Print a weight/fee estimate for a command instead of submitting it.
+    /// In simulation mode the estimate is a placeholder.
+    #[clap(long, global = true)]
+    estimate: bool,
+}
+
+impl Cli {
+    /// Parses `args`, filling global options not given as flags from `env`.
+    ///
+    /// `env` looks up an environment variable by name; empty values are treated as unset.
+    /// Taking the lookup as a parameter keeps parsing independent of the process environment.
+    fn parse_with_env<I, T>(args: I, env: impl Fn(&str) -> Option<String>) -> Result<Self, clap::Error>
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<OsString> + Clone,
+    {
+        let lookup = |key: &str| env(key).filter(|value| !value.is_empty());
+        let mut cli = Self::try_parse_from(args)?;
+        cli.node_url = cli.node_url.or_else(|| lookup(NODE_URL_ENV));
+        cli.contract_address = cli.contract_address.or_else(|| lookup(CONTRACT_ADDRESS_ENV));
+        Ok(cli)
+    }
+
+    /// Returns the node URL, falling back to the local development node.
+    fn node_url(&self) -> &str {
+        self.node_url.as_deref().unwrap_or(DEFAULT_NODE_URL)
+    }
 
-    #[clap(long, default_value = "5C4hrfjw9DjXZTzV3MwzstNcxkN6odQVsreqgradKZLGHG8L")] // Dummy contract address
-    contract_address: String,
+    /// Returns the contract address, falling back to the dummy development address.
+    fn contract_address(&self) -> &str {
+        self.contract_address.as_deref().unwrap_or(DEFAULT_CONTRACT_ADDRESS)
+    }
 }
 
 /// Enum defining all available subcommands for the CLI.
@@ -117,6 +163,9 @@ enum Commands {
         /// Tax rate percentage (0-100).
 
         rate: u8,
+        /// Optional block number from which the rate applies; defaults to immediately.
+        #[clap(long)]
+        effective_from: Option<u32>,
     },
 
     // Pensioner commands
@@ -170,6 +219,414 @@ enum Commands {
     /// General: Get the contract owner.
     /// This is a public query.
     GetContractOwner,
+    /// General: Compare two pensioners' records field by field.
+    /// Fetches both records and prints every field, marking mismatches.
+    Compare {
+        /// The AccountId (as a string) of the first pensioner.
+        a: String,
+        /// The AccountId (as a string) of the second pensioner.
+        b: String,
+    },
+    /// General: Fetch a pensioner's on-chain attestation (record, block and hash).
+    /// This is a public query.
+    Attest {
+        /// The AccountId (as a string) of the pensioner to attest.
+        pensioner_id: String,
+    },
+    /// General: Verify a pensioner's attestation against an expected hash.
+    /// Fetches the attested record and block, recomputes the hash locally and compares.
+    VerifyAttest {
+        /// The AccountId (as a string) of the attested pensioner.
+        pensioner_id: String,
+        /// The expected attestation hash, hex-encoded with a `0x` prefix.
+        expected_hash: String,
+    },
+    /// General: Subscribe to the contract's events and print each decoded event as it arrives,
+    /// one line per event, until interrupted.
+    /// In simulation mode a canned stream is replayed instead of the node's live events.
+    Subscribe {
+        /// File of decoded events replayed in simulation mode, one JSON object per line.
+        /// Defaults to the bundled `fixtures/events.jsonl`.
+        #[clap(long)]
+        fixtures: Option<String>,
+    },
+    /// General: Print a recorded stream of decoded contract events, one line per event.
+    /// Reads the events from a file; does not contact the node.
+    ReplayEvents {
+        /// Path to a file of decoded events, one JSON object per line (see `fixtures/events.jsonl`).
+        file: String,
+    },
+
+    // Utility commands
+    /// Utility: Print a shell completion script to stdout.
+    /// Does not contact the node or the contract.
+    Completions {
+        /// The target shell ("bash", "zsh", "fish" or "powershell").
+        shell: String,
+    },
+    /// Utility: Generate a new sr25519 account.
+    /// Prints the SS58 address and secret seed; does not contact the node or the contract.
+    GenerateAccount {
+        /// Optional path to write a keystore file holding the address and secret seed.
+        #[clap(long)]
+        out: Option<String>,
+    },
+}
+
+/// Writes the completion script for the given shell name to `out`.
+///
+/// # Arguments
+/// * `shell`: The shell name, e.g. "bash" (case-insensitive).
+/// * `out`: Destination of the generated script.
+///
+/// # Returns
+/// `Ok(())` on success, or a `String` error message if the shell is not supported.
+fn write_completions(shell: &str, out: &mut dyn std::io::Write) -> Result<(), String> {
+    let shell: Shell = shell.parse().map_err(|_| {
+        format!("Unsupported shell '{}'. Expected one of: bash, zsh, fish, powershell.", shell)
+    })?;
+    let mut command = Cli::command();
+    let bin_name = command.get_name().to_string();
+    clap_complete::generate(shell, &mut command, bin_name, out);
+    Ok(())
+}
+
+/// Keystore file written by `generate-account`.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+struct KeystoreFile {
+    /// SS58 address of the account.
+    address: String,
+    /// Hex-encoded (`0x`-prefixed) sr25519 secret seed.
+    secret_seed: String,
+}
+
+/// Generates a fresh sr25519 keypair.
+///
+/// # Returns
+/// A `KeystoreFile` holding the SS58 address and hex-encoded secret seed.
+fn generate_account() -> KeystoreFile {
+    let (pair, seed) = sr25519::Pair::generate();
+    KeystoreFile {
+        address: pair.public().to_ss58check(),
+        secret_seed: format!("0x{}", hex::encode(seed)),
+    }
+}
+
+/// Derives the SS58 address belonging to a hex-encoded sr25519 secret seed.
+///
+/// # Returns
+/// The address, or a `String` error message if the seed is malformed.
+fn address_from_seed(secret_seed: &str) -> Result<String, String> {
+    let bytes = hex::decode(secret_seed.trim_start_matches("0x"))
+        .map_err(|e| format!("Invalid secret seed: {}", e))?;
+    let pair = sr25519::Pair::from_seed_slice(&bytes)
+        .map_err(|e| format!("Invalid secret seed: {:?}", e))?;
+    Ok(pair.public().to_ss58check())
+}
+
+/// Writes a keystore file as pretty-printed JSON.
+/// On unix a newly created file is readable and writable by the owner only (mode 0600).
+fn write_keystore(path: &str, keystore: &KeystoreFile) -> Result<(), String> {
+    use std::io::Write;
+
+    let contents = serde_json::to_string_pretty(keystore)
+        .map_err(|e| format!("Failed to serialize keystore: {}", e))?;
+    let mut options = std::fs::OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+    let mut file = options.open(path).map_err(|e| format!("Failed to write keystore '{}': {}", path, e))?;
+    file.write_all(contents.as_bytes())
+        .map_err(|e| format!("Failed to write keystore '{}': {}", path, e))
+}
+
+/// Reads a keystore file written by `write_keystore`.
+fn read_keystore(path: &str) -> Result<KeystoreFile, String> {
+    let contents = std::fs::read_to_string(path)
+        .map_err(|e| format!("Failed to read keystore '{}': {}", path, e))?;
+    serde_json::from_str(&contents).map_err(|e| format!("Invalid keystore '{}': {}", path, e))
+}
+
+/// Client-side view of the contract's `PensionerData`, as returned by `get_pensioner_data`.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+struct PensionerRecord {
+    years_worked: u32,
+    current_salary: u128,
+    status: String,
+    is_deceased: bool,
+    is_receiving_pension: bool,
+    is_eligible_for_payout_age_wise: bool,
+    pension_payout_amount: Option<u128>,
+    spouse_beneficiary: Option<String>,
+    #[serde(default)]
+    survivor_of: Option<String>,
+    #[serde(default = "full_time_bps")]
+    part_time_bps: u32,
+}
+
+/// Default `part_time_bps` for records from contracts that predate part-time factors.
+fn full_time_bps() -> u32 {
+    10_000
+}
+
+/// One field of a side-by-side comparison of two `PensionerRecord`s.
+#[derive(Debug, Clone, PartialEq)]
+struct FieldDiff {
+    /// Name of the compared field.
+    field: &'static str,
+    /// Value in the first record, formatted for display.
+    left: String,
+    /// Value in the second record, formatted for display.
+    right: String,
+}
+
+impl FieldDiff {
+    /// Returns true if the two records disagree on this field.
+    fn is_mismatch(&self) -> bool {
+        self.left != self.right
+    }
+}
+
+/// Compares two pensioner records and returns one `FieldDiff` per field, in declaration order.
+fn diff_pensioner_records(a: &PensionerRecord, b: &PensionerRecord) -> Vec<FieldDiff> {
+    fn field(field: &'static str, left: impl std::fmt::Debug, right: impl std::fmt::Debug) -> FieldDiff {
+        FieldDiff { field, left: format!("{:?}", left), right: format!("{:?}", right) }
+    }
+    vec![
+        field("years_worked", a.years_worked, b.years_worked),
+        field("current_salary", a.current_salary, b.current_salary),
+        field("status", &a.status, &b.status),
+        field("is_deceased", a.is_deceased, b.is_deceased),
+        field("is_receiving_pension", a.is_receiving_pension, b.is_receiving_pension),
+        field("is_eligible_for_payout_age_wise", a.is_eligible_for_payout_age_wise, b.is_eligible_for_payout_age_wise),
+        field("pension_payout_amount", a.pension_payout_amount, b.pension_payout_amount),
+        field("spouse_beneficiary", &a.spouse_beneficiary, &b.spouse_beneficiary),
+        field("survivor_of", &a.survivor_of, &b.survivor_of),
+        field("part_time_bps", a.part_time_bps, b.part_time_bps),
+    ]
+}
+
+/// Decodes an account given as an SS58 address or a `0x`-prefixed hex public key.
+fn account_bytes(account: &str) -> Result<[u8; 32], String> {
+    if let Some(hex_key) = account.strip_prefix("0x") {
+        let bytes = hex::decode(hex_key).map_err(|e| format!("Invalid account '{}': {}", account, e))?;
+        return bytes.try_into().map_err(|_| format!("Invalid account '{}': expected 32 bytes", account));
+    }
+    sp_core::crypto::AccountId32::from_ss58check(account)
+        .map(Into::into)
+        .map_err(|e| format!("Invalid account '{}': {:?}", account, e))
+}
+
+/// SCALE-encodes a pensioner record and block number exactly as the contract's
+/// `attest_pensioner` does for `(PensionerData, u32)`.
+///
+/// # Returns
+/// The encoded bytes, or a `String` error message if the status or an account is malformed.
+fn encode_attestation(record: &PensionerRecord, block_number: u32) -> Result<Vec<u8>, String> {
+    fn encode_account(out: &mut Vec<u8>, account: &Option<String>) -> Result<(), String> {
+        match account {
+            Some(account) => {
+                out.push(1);
+                out.extend_from_slice(&account_bytes(account)?);
+            }
+            None => out.push(0),
+        }
+        Ok(())
+    }
+
+    let mut out = Vec::new();
+    out.extend_from_slice(&record.years_worked.to_le_bytes());
+    out.extend_from_slice(&record.current_salary.to_le_bytes());
+    out.push(match record.status.as_str() {
+        "Active" => 0,
+        "LongTermPause" => 1,
+        "LaidOff" => 2,
+        other => return Err(format!("Unknown employment status '{}'", other)),
+    });
+    out.push(u8::from(record.is_deceased));
+    out.push(u8::from(record.is_receiving_pension));
+    out.push(u8::from(record.is_eligible_for_payout_age_wise));
+    match record.pension_payout_amount {
+        Some(amount) => {
+            out.push(1);
+            out.extend_from_slice(&amount.to_le_bytes());
+        }
+        None => out.push(0),
+    }
+    encode_account(&mut out, &record.spouse_beneficiary)?;
+    encode_account(&mut out, &record.survivor_of)?;
+    out.extend_from_slice(&record.part_time_bps.to_le_bytes());
+    out.extend_from_slice(&block_number.to_le_bytes());
+    Ok(out)
+}
+
+/// Computes the attestation hash (BLAKE2b-256 of the SCALE encoding) for a record and block.
+///
+/// # Returns
+/// The hash, hex-encoded with a `0x` prefix, or a `String` error message if the record cannot be encoded.
+fn attestation_hash(record: &PensionerRecord, block_number: u32) -> Result<String, String> {
+    let encoded = encode_attestation(record, block_number)?;
+    Ok(format!("0x{}", hex::encode(sp_core::hashing::blake2_256(&encoded))))
+}
+
+/// Recomputes the attestation hash for a record and block and compares it with `expected_hash`.
+///
+/// # Returns
+/// `Ok(())` if the hashes match (case-insensitively), or a `String` error message describing the mismatch.
+fn verify_attestation_hash(record: &PensionerRecord, block_number: u32, expected_hash: &str) -> Result<(), String> {
+    let computed = attestation_hash(record, block_number)?;
+    if computed.eq_ignore_ascii_case(expected_hash) {
+        Ok(())
+    } else {
+        Err(format!("Attestation hash mismatch: expected {}, computed {}", expected_hash, computed))
+    }
+}
+
+/// Fetches a pensioner's attestation and verifies it against `expected_hash` locally.
+///
+/// # Returns
+/// A JSON summary with the verified block number, or a `String` error message if the
+/// attestation cannot be fetched or decoded, or the hash does not match.
+async fn verify_attestation(
+    client: &RpcClient,
+    contract_address: &str,
+    pensioner_id: &str,
+    expected_hash: &str,
+) -> Result<serde_json::Value, String> {
+    let response = client
+        .call_contract_query(contract_address, "attest_pensioner", json!({ "pensioner_id": pensioner_id }), "any_caller_for_query")
+        .await?;
+    let attestation = response.get("data").ok_or_else(|| format!("No attestation returned for pensioner '{}'", pensioner_id))?;
+    let record: PensionerRecord = serde_json::from_value(attestation.get("record").cloned().unwrap_or_default())
+        .map_err(|e| format!("Could not decode attestation for '{}': {}", pensioner_id, e))?;
+    let block_number = attestation
+        .get("block_number")
+        .and_then(|block| block.as_u64())
+        .and_then(|block| u32::try_from(block).ok())
+        .ok_or_else(|| format!("Attestation for '{}' has no valid block_number", pensioner_id))?;
+
+    verify_attestation_hash(&record, block_number, expected_hash)?;
+    Ok(json!({ "success": true, "data": { "verified": true, "block_number": block_number } }))
+}
+
+/// Fetches two pensioners' records, prints a field-by-field diff, and returns a summary.
+///
+/// # Returns
+/// A JSON summary with the number of mismatching fields, or a `String` error message
+/// if either record cannot be fetched or decoded.
+async fn compare_pensioners(
+    client: &RpcClient,
+    contract_address: &str,
+    a: &str,
+    b: &str,
+) -> Result<serde_json::Value, String> {
+    let mut records = Vec::new();
+    for pensioner_id in [a, b] {
+        let response = client
+            .call_contract_query(contract_address, "get_pensioner_data", json!({ "pensioner_id": pensioner_id }), "any_caller_for_query")
+            .await?;
+        let data = response.get("data").cloned().ok_or_else(|| format!("No data returned for pensioner '{}'", pensioner_id))?;
+        let record: PensionerRecord = serde_json::from_value(data)
+            .map_err(|e| format!("Could not decode pensioner '{}': {}", pensioner_id, e))?;
+        records.push(record);
+    }
+
+    let diffs = diff_pensioner_records(&records[0], &records[1]);
+    println!("{:<34} {:<24} {:<24}", "field", a, b);
+    for diff in &diffs {
+        let marker = if diff.is_mismatch() { "!=" } else { "  " };
+        println!("{} {:<31} {:<24} {:<24}", marker, diff.field, diff.left, diff.right);
+    }
+    let mismatches = diffs.iter().filter(|diff| diff.is_mismatch()).count();
+    Ok(json!({ "success": true, "data": { "mismatches": mismatches } }))
+}
+
+/// A decoded contract event, as replayed from a recorded stream.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+struct ContractEvent {
+    /// Block in which the event was emitted.
+    block_number: u32,
+    /// Name of the event, e.g. "TaxSettled".
+    name: String,
+    /// The event's fields by name.
+    #[serde(default)]
+    fields: serde_json::Map<String, serde_json::Value>,
+}
+
+/// Decodes a single event from its JSON representation.
+fn decode_event(line: &str) -> Result<ContractEvent, String> {
+    serde_json::from_str(line).map_err(|e| format!("Could not decode event '{}': {}", line, e))
+}
+
+/// Formats an event as a single line: block, name, then `field=value` pairs in field-name order.
+fn format_event_line(event: &ContractEvent) -> String {
+    let mut line = format!("#{} {}", event.block_number, event.name);
+    for (field, value) in &event.fields {
+        match value {
+            serde_json::Value::String(text) => line.push_str(&format!(" {}={}", field, text)),
+            other => line.push_str(&format!(" {}={}", field, other)),
+        }
+    }
+    line
+}
+
+/// Canned event stream replayed by `subscribe` in simulation mode when no fixtures file is given.
+const BUNDLED_EVENT_FIXTURES: &str = include_str!("../fixtures/events.jsonl");
+
+/// Decodes and prints each event of a stream, one JSON object per line; blank lines are skipped.
+///
+/// # Returns
+/// The number of events printed, or a `String` error message if an event cannot be decoded.
+fn print_event_stream(contents: &str) -> Result<usize, String> {
+    let mut printed = 0;
+    for line in contents.lines().filter(|line| !line.trim().is_empty()) {
+        println!("{}", format_event_line(&decode_event(line)?));
+        printed += 1;
+    }
+    Ok(printed)
+}
+
+/// Prints each event of a recorded stream and returns a summary once the stream ends.
+///
+/// # Returns
+/// A JSON summary with the number of events printed, or a `String` error message if the
+/// file cannot be read or an event cannot be decoded.
+fn replay_events(path: &str) -> Result<serde_json::Value, String> {
+    let contents = std::fs::read_to_string(path).map_err(|e| format!("Could not read fixtures '{}': {}", path, e))?;
+    let printed = print_event_stream(&contents)?;
+    Ok(json!({ "success": true, "data": { "events": printed } }))
+}
+
+/// Weight required by a contract call, as reported by a `ContractsApi_call` dry-run.
+#[derive(Serialize, Debug, Clone, PartialEq)]
+struct GasEstimate {
+    /// Computation weight (`refTime`).
+    ref_time: u64,
+    /// Proof size weight (`proofSize`).
+    proof_size: u64,
+}
+
+/// Extracts `gasRequired` from a `ContractsApi_call` dry-run response.
+///
+/// Accepts either a full JSON-RPC response (with the dry-run under `result`) or the bare dry-run object.
+///
+/// # Returns
+/// The `GasEstimate`, or a `String` error message if `gasRequired` is missing or malformed.
+fn parse_gas_required(response: &serde_json::Value) -> Result<GasEstimate, String> {
+    let gas_required = response
+        .get("gasRequired")
+        .or_else(|| response.get("result").and_then(|dry_run| dry_run.get("gasRequired")))
+        .ok_or("Dry-run response has no gasRequired")?;
+    let weight = |field: &str| {
+        gas_required
+            .get(field)
+            .and_then(|value| value.as_u64())
+            .ok_or_else(|| format!("Dry-run gasRequired has no numeric {}", field))
+    };
+    Ok(GasEstimate { ref_time: weight("refTime")?, proof_size: weight("proofSize")? })
 }
 
 /// `RpcClient` is responsible for simulating interactions with the smart contract.
@@ -181,6 +638,8 @@ struct RpcClient {
     node_url: String,
     /// HTTP client for making requests. Not fully utilized in simulation mode.
     client: reqwest::Client,
+    /// If true, commands are dry-run for a weight/fee estimate instead of being submitted.
+    estimate_only: bool,
 }
 
 impl RpcClient {
@@ -188,10 +647,12 @@ impl RpcClient {
     ///
     /// # Arguments
     /// * `node_url`: The URL of the target Substrate node.
-    pub fn new(node_url: String) -> Self {
+    /// * `estimate_only`: If true, commands return an estimate instead of being submitted.
+    pub fn new(node_url: String, estimate_only: bool) -> Self {
         RpcClient {
             node_url,
             client: reqwest::Client::new(),
+            estimate_only,
         }
     }
 
@@ -240,6 +701,41 @@ impl RpcClient {
                     "spouse_beneficiary": null
                 }
             })),
+            "attest_pensioner" => {
+                let record = PensionerRecord {
+                    years_worked: 10,
+                    current_salary: 50000,
+                    status: String::from("Active"),
+                    is_deceased: false,
+                    is_receiving_pension: false,
+                    is_eligible_for_payout_age_wise: false,
+                    pension_payout_amount: None,
+                    spouse_beneficiary: None,
+                    survivor_of: None,
+                    part_time_bps: 10_000,
+                };
+                let block_number = 1;
+                let hash = attestation_hash(&record, block_number)?;
+                Ok(json!({
+                    "success": true,
+                    "data": {
+                        "record": {
+                            "years_worked": record.years_worked,
+                            "current_salary": record.current_salary,
+                            "status": record.status,
+                            "is_deceased": record.is_deceased,
+                            "is_receiving_pension": record.is_receiving_pension,
+                            "is_eligible_for_payout_age_wise": record.is_eligible_for_payout_age_wise,
+                            "pension_payout_amount": record.pension_payout_amount,
+                            "spouse_beneficiary": record.spouse_beneficiary,
+                            "survivor_of": record.survivor_of,
+                            "part_time_bps": record.part_time_bps
+                        },
+                        "block_number": block_number,
+                        "hash": hash
+                    }
+                }))
+            }
             "get_my_future_payout" => {
                 Ok(json!({"success": true, "data": {"estimated_payout": 12345, "currency": "Units"} }))
             }
@@ -262,6 +758,9 @@ impl RpcClient {
     /// * `params`: JSON-formatted parameters for the contract call.
     /// * `caller_id`: The AccountId (as a string) of the entity simulating the transaction.
     ///
+    /// If the client is in estimate-only mode, the command is not submitted and a placeholder
+    /// weight estimate is returned instead; a predicted fee requires a live node.
+    ///
     /// # Returns
     /// A `Result` containing a `serde_json::Value` representing the simulated
     /// transaction success (with a fake hash), or a `String` error message.
@@ -277,6 +776,16 @@ impl RpcClient {
             caller_id, method_name, contract_address, self.node_url, params
         );
         
+        if self.estimate_only {
+            // Placeholder for the `ContractsApi_call` dry-run a live node would answer.
+            let dry_run = json!({ "gasRequired": { "refTime": 1_000_000_000u64, "proofSize": 65_536u64 } });
+            let gas_required = parse_gas_required(&dry_run)?;
+            return Ok(json!({
+                "success": true,
+                "estimate": { "gas_required": gas_required, "predicted_fee": null }
+            }));
+        }
+
         let mut rng = rand::thread_rng();
         let random_num: u32 = rng.gen();
 
@@ -285,6 +794,39 @@ impl RpcClient {
             "transaction_hash": format!("simulated_tx_hash_{:x}", random_num)
         }))
     }
+
+    /// Simulates a subscription to the smart contract's events.
+    ///
+    /// Prints each decoded event as it arrives until the stream ends or the user interrupts
+    /// with Ctrl-C. In simulation mode the stream is replayed from `fixtures`, or from the
+    /// bundled `fixtures/events.jsonl` when none is given, using the same decoding and
+    /// formatting as `replay-events`.
+    ///
+    /// # Arguments
+    /// * `contract_address`: The address of the target smart contract (string).
+    /// * `fixtures`: Optional path of the event stream to replay.
+    ///
+    /// # Returns
+    /// A JSON summary with the number of events printed, or a `String` error message if the
+    /// fixtures cannot be read or an event cannot be decoded.
+    async fn subscribe_events(&self, contract_address: &str, fixtures: Option<&str>) -> Result<serde_json::Value, String> {
+        println!(
+            "Simulating SUBSCRIBE to events of contract '{}' at URL '{}'. Press Ctrl-C to stop.",
+            contract_address, self.node_url
+        );
+        let contents = match fixtures {
+            Some(path) => tokio::fs::read_to_string(path)
+                .await
+                .map_err(|e| format!("Could not read fixtures '{}': {}", path, e))?,
+            None => BUNDLED_EVENT_FIXTURES.to_string(),
+        };
+        tokio::select! {
+            printed = async { print_event_stream(&contents) } => {
+                printed.map(|printed| json!({ "success": true, "data": { "events": printed } }))
+            }
+            _ = tokio::signal::ctrl_c() => Ok(json!({ "success": true, "data": { "interrupted": true } })),
+        }
+    }
 }
 
 /// Main entry point for the off-chain client application.
@@ -294,10 +836,32 @@ impl RpcClient {
 /// Prints the result of the simulated operation to the console.
 #[tokio::main]
 async fn main() {
-    let cli = Cli::parse();
-    let client = RpcClient::new(cli.node_url.clone());
+    let cli = Cli::parse_with_env(std::env::args_os(), |key| std::env::var(key).ok()).unwrap_or_else(|e| e.exit());
+
+    // Completions are generated locally and bypass the RPC client entirely.
+    if let Commands::Completions { shell } = &cli.command {
+        if let Err(e) = write_completions(shell, &mut std::io::stdout()) {
+            eprintln!("Operation failed: {}", e);
+        }
+        return;
+    }
 
-    let contract_address = &cli.contract_address;
+    // Account generation is local as well.
+    if let Commands::GenerateAccount { out } = &cli.command {
+        let keystore = generate_account();
+        println!("Address: {}\nSecret seed: {}", keystore.address, keystore.secret_seed);
+        if let Some(path) = out {
+            match write_keystore(path, &keystore) {
+                Ok(()) => println!("Keystore written to {}", path),
+                Err(e) => eprintln!("Operation failed: {}", e),
+            }
+        }
+        return;
+    }
+
+    let client = RpcClient::new(cli.node_url().to_string(), cli.estimate);
+
+    let contract_address = cli.contract_address();
     let result = match cli.command {
         Commands::RegisterCompany { company_id } => {
             let params = json!({ "company_id": company_id });
@@ -332,10 +896,11 @@ async fn main() {
             });
             client.call_contract_command(contract_address, "add_pension_insurance", params, &bank_id_as_caller).await
         }
-        Commands::SetTax { office_id_as_caller, pensioner_id, rate } => {
+        Commands::SetTax { office_id_as_caller, pensioner_id, rate, effective_from } => {
             let params = json!({
                 "pensioner_id": pensioner_id,
-                "tax_rate_percentage": rate
+                "tax_rate_percentage": rate,
+                "effective_from": effective_from
             });
             client.call_contract_command(contract_address, "apply_pension_tax_rate", params, &office_id_as_caller).await
         }
@@ -367,6 +932,23 @@ async fn main() {
         Commands::GetContractOwner => {
             client.call_contract_query(contract_address, "get_contract_owner", json!({}), "any_caller_for_query").await
         }
+        Commands::Compare { a, b } => {
+            compare_pensioners(&client, contract_address, &a, &b).await
+        }
+        Commands::Attest { pensioner_id } => {
+            let params = json!({ "pensioner_id": pensioner_id });
+            client.call_contract_query(contract_address, "attest_pensioner", params, "any_caller_for_query").await
+        }
+        Commands::VerifyAttest { pensioner_id, expected_hash } => {
+            verify_attestation(&client, contract_address, &pensioner_id, &expected_hash).await
+        }
+        Commands::Subscribe { fixtures } => {
+            client.subscribe_events(contract_address, fixtures.as_deref()).await
+        }
+        Commands::ReplayEvents { file } => replay_events(&file),
+        Commands::Completions { .. } | Commands::GenerateAccount { .. } => {
+            unreachable!("local commands are handled before dispatch")
+        }
     };
 
     match result {
@@ -374,3 +956,218 @@ async fn main() {
         Err(e) => eprintln!("Operation failed: {}", e),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn env_vars_populate_global_options_and_flags_override() {
+        let env: std::collections::HashMap<&str, &str> = [
+            (NODE_URL_ENV, "http://env-node:9944"),
+            (CONTRACT_ADDRESS_ENV, "5EnvContractAddress"),
+        ]
+        .into_iter()
+        .collect();
+        let lookup = |key: &str| env.get(key).map(|value| value.to_string());
+
+        let cli = Cli::parse_with_env(["offchain_client", "get-contract-owner"], lookup).unwrap();
+        assert_eq!(cli.node_url(), "http://env-node:9944");
+        assert_eq!(cli.contract_address(), "5EnvContractAddress");
+
+        let cli = Cli::parse_with_env(
+            [
+                "offchain_client",
+                "--node-url",
+                "http://flag-node:9944",
+                "--contract-address",
+                "5FlagContractAddress",
+                "get-contract-owner",
+            ],
+            lookup,
+        )
+        .unwrap();
+        assert_eq!(cli.node_url(), "http://flag-node:9944");
+        assert_eq!(cli.contract_address(), "5FlagContractAddress");
+
+        // Unset or empty variables fall back to the defaults
+        let cli = Cli::parse_with_env(["offchain_client", "get-contract-owner"], |key| {
+            (key == NODE_URL_ENV).then(String::new)
+        })
+        .unwrap();
+        assert_eq!(cli.node_url(), DEFAULT_NODE_URL);
+        assert_eq!(cli.contract_address(), DEFAULT_CONTRACT_ADDRESS);
+    }
+
+    #[test]
+    fn bash_completions_are_generated() {
+        let mut out = Vec::new();
+        assert_eq!(write_completions("bash", &mut out), Ok(()));
+        let script = String::from_utf8(out).unwrap();
+        assert!(!script.is_empty());
+        assert!(script.contains("offchain_client"));
+    }
+
+    fn sample_record() -> PensionerRecord {
+        PensionerRecord {
+            years_worked: 10,
+            current_salary: 50000,
+            status: String::from("Active"),
+            is_deceased: false,
+            is_receiving_pension: false,
+            is_eligible_for_payout_age_wise: false,
+            pension_payout_amount: None,
+            spouse_beneficiary: None,
+            survivor_of: None,
+            part_time_bps: 10_000,
+        }
+    }
+
+    #[test]
+    fn diff_flags_salary_and_status_mismatches() {
+        let a = sample_record();
+        let mut b = sample_record();
+        b.current_salary = 55000;
+        b.status = String::from("LaidOff");
+
+        let diffs = diff_pensioner_records(&a, &b);
+        let mismatched: Vec<&str> = diffs.iter().filter(|d| d.is_mismatch()).map(|d| d.field).collect();
+        assert_eq!(mismatched, vec!["current_salary", "status"]);
+
+        let salary = diffs.iter().find(|d| d.field == "current_salary").unwrap();
+        assert_eq!(salary.left, "50000");
+        assert_eq!(salary.right, "55000");
+    }
+
+    #[test]
+    fn diff_flags_survivor_of_mismatch() {
+        let a = sample_record();
+        let mut b = sample_record();
+        b.survivor_of = Some(String::from("5Deceased"));
+
+        let diffs = diff_pensioner_records(&a, &b);
+        let mismatched: Vec<&str> = diffs.iter().filter(|d| d.is_mismatch()).map(|d| d.field).collect();
+        assert_eq!(mismatched, vec!["survivor_of"]);
+    }
+
+    #[test]
+    fn diff_of_identical_records_has_no_mismatches() {
+        let diffs = diff_pensioner_records(&sample_record(), &sample_record());
+        // One diff per serialized field, so new fields must be added to the comparison too
+        let field_count = serde_json::to_value(sample_record()).unwrap().as_object().unwrap().len();
+        assert_eq!(diffs.len(), field_count);
+        assert!(diffs.iter().all(|d| !d.is_mismatch()));
+    }
+
+    #[test]
+    fn unknown_shell_is_rejected() {
+        let mut out = Vec::new();
+        assert!(write_completions("tcsh", &mut out).is_err());
+        assert!(out.is_empty());
+    }
+
+    #[test]
+    fn generated_accounts_are_distinct() {
+        let first = generate_account();
+        let second = generate_account();
+        assert_ne!(first.address, second.address);
+        assert_eq!(address_from_seed(&first.secret_seed), Ok(first.address));
+    }
+
+    #[test]
+    fn keystore_file_round_trips_to_the_same_address() {
+        let keystore = generate_account();
+        let path = std::env::temp_dir().join(format!("pension_keystore_{}.json", rand::random::<u64>()));
+        let path = path.to_str().unwrap();
+
+        assert_eq!(write_keystore(path, &keystore), Ok(()));
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = std::fs::metadata(path).unwrap().permissions().mode();
+            assert_eq!(mode & 0o777, 0o600);
+        }
+        let loaded = read_keystore(path).unwrap();
+        std::fs::remove_file(path).unwrap();
+
+        assert_eq!(loaded, keystore);
+        assert_eq!(address_from_seed(&loaded.secret_seed), Ok(keystore.address));
+    }
+
+    #[test]
+    fn gas_required_is_parsed_from_dry_run_response() {
+        let response = json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "result": {
+                "gasConsumed": { "refTime": 812_000_000u64, "proofSize": 16_384u64 },
+                "gasRequired": { "refTime": 1_234_567_890u64, "proofSize": 32_768u64 },
+                "storageDeposit": { "Charge": 0 },
+                "result": { "Ok": { "flags": 0, "data": "0x00" } }
+            }
+        });
+        assert_eq!(
+            parse_gas_required(&response),
+            Ok(GasEstimate { ref_time: 1_234_567_890, proof_size: 32_768 })
+        );
+        assert!(parse_gas_required(&json!({ "result": { "gasConsumed": {} } })).is_err());
+        assert!(parse_gas_required(&json!({ "gasRequired": { "refTime": "x", "proofSize": 1 } })).is_err());
+    }
+
+    #[test]
+    fn estimate_flag_is_global() {
+        let cli = Cli::try_parse_from(["offchain_client", "register-company", "5Company", "--estimate"]).unwrap();
+        assert!(cli.estimate);
+    }
+
+    #[test]
+    fn matching_attestation_verifies_and_tampered_field_fails() {
+        let mut record = sample_record();
+        record.pension_payout_amount = Some(10000);
+        record.spouse_beneficiary = Some(generate_account().address);
+        let hash = attestation_hash(&record, 42).unwrap();
+        assert_eq!(verify_attestation_hash(&record, 42, &hash), Ok(()));
+        assert_eq!(verify_attestation_hash(&record, 42, &hash.to_uppercase()), Ok(()));
+
+        let mut tampered = record.clone();
+        tampered.current_salary += 1;
+        assert!(verify_attestation_hash(&tampered, 42, &hash).is_err());
+        assert!(verify_attestation_hash(&record, 43, &hash).is_err());
+    }
+
+    #[test]
+    fn attestation_encoding_matches_scale_layout() {
+        let encoded = encode_attestation(&sample_record(), 7).unwrap();
+        // u32 + u128 + status + 3 bools + 3 empty options + u32 part-time factor + u32 block
+        assert_eq!(encoded.len(), 4 + 16 + 1 + 3 + 3 + 4 + 4);
+        assert_eq!(&encoded[..4], &10u32.to_le_bytes());
+        assert_eq!(&encoded[encoded.len() - 4..], &7u32.to_le_bytes());
+
+        let mut unknown = sample_record();
+        unknown.status = String::from("Retired");
+        assert!(encode_attestation(&unknown, 7).is_err());
+    }
+
+    #[test]
+    fn payout_initiated_event_is_formatted_as_one_line() {
+        let event = decode_event(
+            r#"{"block_number": 42, "name": "PayoutInitiated", "fields": {"pensioner_id": "5Bob", "amount": 10000}}"#,
+        )
+        .unwrap();
+        assert_eq!(format_event_line(&event), "#42 PayoutInitiated amount=10000 pensioner_id=5Bob");
+    }
+
+    #[test]
+    fn tax_settled_event_is_formatted_as_one_line() {
+        let event = decode_event(
+            r#"{"block_number": 15, "name": "TaxSettled", "fields": {"tax_office_id": "5Frank", "amount": 1200}}"#,
+        )
+        .unwrap();
+        assert_eq!(format_event_line(&event), "#15 TaxSettled amount=1200 tax_office_id=5Frank");
+
+        for line in include_str!("../fixtures/events.jsonl").lines() {
+            assert!(decode_event(line).is_ok());
+        }
+        assert!(decode_event("not json").is_err());
+    }
+}